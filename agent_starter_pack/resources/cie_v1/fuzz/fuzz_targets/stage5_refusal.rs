@@ -0,0 +1,102 @@
+//! honggfuzz harness for the Stage-5 refusal contract.
+//!
+//! Run via `cargo hfuzz run stage5_refusal` from the `fuzz/` directory.
+//! Drives `stage5_refusal_contract` with randomized inputs (via `arbitrary`)
+//! and asserts the constitutional invariants the unit tests only spot-check:
+//! no panics (including on NaN/inf telemetry), no mutation of `scene_before`,
+//! determinism, "findings non-empty implies HALT", and sorted findings.
+//! See `stage5_refusal::property_tests` for the `proptest` mirror that runs
+//! these same invariants in normal CI.
+
+#[macro_use]
+extern crate honggfuzz;
+
+use arbitrary::{Arbitrary, Unstructured};
+use cie_v1::stage5_refusal::{
+    stage5_refusal_contract, ArmPhase, ContaminationClass, ContaminationTelemetry, EmulatorConfig,
+    RefusalFinding, RefusalPolicy, SceneCapsule,
+};
+
+#[derive(Debug, Arbitrary)]
+struct FuzzInput {
+    scene_before: SceneCapsule,
+    /// When false, `scene_after` is `scene_before.clone()` (the common
+    /// "unmutated" path); when true it's independently arbitrary, so the
+    /// harness also exercises the mutation-detection finding.
+    scene_after_mutated: bool,
+    scene_after_arbitrary: SceneCapsule,
+    cfg: EmulatorConfig,
+    tel: ContaminationTelemetry,
+    policy: RefusalPolicy,
+}
+
+fn class_priority(f: &RefusalFinding) -> u8 {
+    match f.class {
+        ContaminationClass::BiologicalIntrusion => 10,
+        ContaminationClass::ChemicalSpike => 20,
+        ContaminationClass::InstrumentDrift => 30,
+        ContaminationClass::ConfigDrift => 35,
+        ContaminationClass::LineageBreak => 40,
+        ContaminationClass::WorldlineImpossibility => 50,
+    }
+}
+
+fn main() {
+    loop {
+        fuzz!(|data: &[u8]| {
+            let mut u = Unstructured::new(data);
+            let input = match FuzzInput::arbitrary(&mut u) {
+                Ok(input) => input,
+                Err(_) => return,
+            };
+
+            let scene_after = if input.scene_after_mutated {
+                input.scene_after_arbitrary.clone()
+            } else {
+                input.scene_before.clone()
+            };
+
+            let before_snapshot = input.scene_before.clone();
+
+            // (1) Never panics on any input, including NaN/inf telemetry —
+            // a panic here is reported by honggfuzz as a crash.
+            let verdict1 = stage5_refusal_contract(
+                &input.scene_before,
+                &scene_after,
+                &input.cfg,
+                &input.tel,
+                &input.policy,
+                None,
+            );
+
+            // (2) scene_before is bit-identical before and after the call.
+            assert_eq!(
+                input.scene_before, before_snapshot,
+                "SceneCapsule mutated under refusal path"
+            );
+
+            // (3) Determinism: identical inputs yield an equal verdict.
+            let verdict2 = stage5_refusal_contract(
+                &input.scene_before,
+                &scene_after,
+                &input.cfg,
+                &input.tel,
+                &input.policy,
+                None,
+            );
+            assert_eq!(verdict1, verdict2, "stage5_refusal_contract is not deterministic");
+
+            // (4) Non-empty findings implies ok == false and HALT.
+            if !verdict1.findings.is_empty() {
+                assert!(!verdict1.ok);
+                assert_eq!(verdict1.next_phase, ArmPhase::Halted);
+            }
+
+            // (5) findings is always sorted ascending by class-priority key.
+            assert!(verdict1
+                .findings
+                .windows(2)
+                .all(|w| class_priority(&w[0]) <= class_priority(&w[1])));
+        });
+    }
+}
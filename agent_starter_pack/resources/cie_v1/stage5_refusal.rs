@@ -25,6 +25,10 @@ pub enum ContaminationClass {
     BiologicalIntrusion,
     ChemicalSpike,
     InstrumentDrift,
+    /// The sealed `EmulatorConfig.config_hash_sha256` does not match the
+    /// recomputed digest of the runtime parameters actually in force —
+    /// i.e. replay with a swapped or stale policy.
+    ConfigDrift,
     LineageBreak,
     WorldlineImpossibility,
 }
@@ -36,6 +40,7 @@ impl fmt::Display for ContaminationClass {
             BiologicalIntrusion => "BIOLOGICAL_INTRUSION",
             ChemicalSpike => "CHEMICAL_SPIKE",
             InstrumentDrift => "INSTRUMENT_DRIFT",
+            ConfigDrift => "CONFIG_DRIFT",
             LineageBreak => "LINEAGE_BREAK",
             WorldlineImpossibility => "WORLDLINE_IMPOSSIBILITY",
         };
@@ -43,6 +48,22 @@ impl fmt::Display for ContaminationClass {
     }
 }
 
+impl ContaminationClass {
+    /// Stable numeric code for downstream/wire-protocol consumers. Never
+    /// renumber an existing class — append new classes with new codes.
+    pub fn code(&self) -> u16 {
+        use ContaminationClass::*;
+        match self {
+            BiologicalIntrusion => 1,
+            ChemicalSpike => 2,
+            InstrumentDrift => 3,
+            LineageBreak => 4,
+            WorldlineImpossibility => 5,
+            ConfigDrift => 6,
+        }
+    }
+}
+
 /// A single refusal finding. Deterministic text, stable ordering.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RefusalFinding {
@@ -51,6 +72,9 @@ pub struct RefusalFinding {
 }
 
 /// The Stage-5 verdict: either proceed, or HALT with reasons.
+/// `#[must_use]`: reading `.ok` wrong or dropping the verdict must not let a
+/// caller sail past a HALT. Prefer `.into_result()` at guard sites.
+#[must_use]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RefusalVerdict {
     pub ok: bool,
@@ -61,6 +85,7 @@ pub struct RefusalVerdict {
 /// Minimal “SceneCapsule” footprint for Stage-5 enforcement.
 /// Keep it small: you can wrap/bridge to your full scene graph later.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(fuzzing, derive(arbitrary::Arbitrary))]
 pub struct SceneCapsule {
     pub scene_id: String,
     pub world_id: String,
@@ -83,6 +108,7 @@ pub struct SceneCapsule {
 
 /// Minimal config footprint to prevent replay/config drift.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(fuzzing, derive(arbitrary::Arbitrary))]
 pub struct EmulatorConfig {
     /// Deterministic emulator build id (e.g., git commit hash truncated)
     pub build_id: [u8; 20],
@@ -97,6 +123,7 @@ pub struct EmulatorConfig {
 /// Minimal telemetry that can trigger contamination refusal.
 /// (You can enrich later; keep determinism now.)
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(fuzzing, derive(arbitrary::Arbitrary))]
 pub struct ContaminationTelemetry {
     /// Chemical spike detector output (ppm or normalized)
     pub acetaldehyde_ppm: f64,
@@ -115,6 +142,7 @@ pub struct ContaminationTelemetry {
 /// Stage-5 thresholds are *policy*, not code.
 /// Treat as immutable configuration under 2026.GOLD.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(fuzzing, derive(arbitrary::Arbitrary))]
 pub struct RefusalPolicy {
     pub acetaldehyde_ppm_max: f64,
     pub ethanol_ppm_max: f64,
@@ -123,47 +151,367 @@ pub struct RefusalPolicy {
 }
 
 /// A pure-function “before/after” check token.
-/// In your system, you’d compute this via canonicalization + sha256.
+/// Computed via JCS canonicalization + sha256 (see `jcs` and `sha256` below).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CapsuleDigest(pub [u8; 32]);
 
-/// Compute a digest of SceneCapsule (placeholder hook).
-/// Replace with your canonicalization + sha256.
-/// This function is intentionally explicit so you can wire to your existing hash stack.
-pub fn digest_scene_capsule_placeholder(sc: &SceneCapsule) -> CapsuleDigest {
-    // NOTE: Deterministic but intentionally simple placeholder.
-    // Replace with: sha256(JCS(scene_capsule_without_derived_fields))
-    // For now we "fold" a few bytes to produce a stable token.
-    let mut out = [0u8; 32];
-    // scene_id length
-    out[0] = (sc.scene_id.len() & 0xFF) as u8;
-    // world_id length
-    out[1] = (sc.world_id.len() & 0xFF) as u8;
-    // corridor_id length
-    out[2] = (sc.corridor_id.len() & 0xFF) as u8;
-    // finality tag length
-    out[3] = (sc.finality_tag.len() & 0xFF) as u8;
-    // copy some anchor bytes
-    out[4..12].copy_from_slice(&sc.genesis_hash_sha256[0..8]);
-    out[12..20].copy_from_slice(&sc.vaulted_blob_sha256[0..8]);
-    out[20..28].copy_from_slice(&sc.merkle_root[0..8]);
-    out[28] = if sc.stage3_impossible_worldline { 1 } else { 0 };
-    CapsuleDigest(out)
+/// Minimal JSON Canonicalization Scheme (RFC 8785) support, scoped to exactly
+/// what Stage 5 needs to hash: a handful of string/bool/integer/float/
+/// byte-array fields.
+/// No external JSON/crypto crates — this stays dependency-free on purpose
+/// so the refusal contract can't drift with an upstream serde/sha2 release.
+mod jcs {
+    /// A tiny JSON value tree, just expressive enough for `SceneCapsule`
+    /// and the `EmulatorConfig`/`RefusalPolicy` runtime parameters.
+    pub enum Value {
+        Str(String),
+        Bool(bool),
+        /// Plain non-negative integers (e.g. `max_frame_delta_us`) serialize
+        /// as the JCS/ECMAScript decimal form directly.
+        UInt(u64),
+        /// `f64` fields are encoded as their big-endian bit pattern in
+        /// lowercase hex rather than JCS's ECMAScript float-to-string rules:
+        /// that keeps NaN/inf representable and avoids any cross-platform
+        /// float-formatting drift, at the cost of not being a JCS number.
+        F64Bits(f64),
+        /// Byte arrays are opaque to JCS proper; we encode them as lowercase
+        /// hex strings, which is deterministic and JCS-clean.
+        Hex(Vec<u8>),
+        /// Object members are written in the order given here — callers are
+        /// responsible for sorting by UTF-16 code-unit order ascending.
+        Object(Vec<(&'static str, Value)>),
+    }
+
+    /// Escape a string per the minimal JSON escape set (RFC 8785 §3.2.2.2).
+    fn push_escaped_str(out: &mut String, s: &str) {
+        out.push('"');
+        for c in s.chars() {
+            match c {
+                '"' => out.push_str("\\\""),
+                '\\' => out.push_str("\\\\"),
+                '\u{08}' => out.push_str("\\b"),
+                '\u{0C}' => out.push_str("\\f"),
+                '\n' => out.push_str("\\n"),
+                '\r' => out.push_str("\\r"),
+                '\t' => out.push_str("\\t"),
+                c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+                c => out.push(c),
+            }
+        }
+        out.push('"');
+    }
+
+    fn push_hex(out: &mut String, bytes: &[u8]) {
+        out.push('"');
+        for b in bytes {
+            out.push_str(&format!("{b:02x}"));
+        }
+        out.push('"');
+    }
+
+    fn push_value(out: &mut String, v: &Value) {
+        match v {
+            Value::Str(s) => push_escaped_str(out, s),
+            Value::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+            Value::UInt(n) => out.push_str(&n.to_string()),
+            Value::F64Bits(n) => push_hex(out, &n.to_bits().to_be_bytes()),
+            Value::Hex(bytes) => push_hex(out, bytes),
+            Value::Object(members) => {
+                out.push('{');
+                for (i, (key, val)) in members.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    push_escaped_str(out, key);
+                    out.push(':');
+                    push_value(out, val);
+                }
+                out.push('}');
+            }
+        }
+    }
+
+    /// Serialize an already key-sorted `Value::Object` tree to its canonical
+    /// UTF-8 JSON byte string (no insignificant whitespace).
+    pub fn canonicalize(v: &Value) -> Vec<u8> {
+        let mut out = String::new();
+        push_value(&mut out, v);
+        out.into_bytes()
+    }
+}
+
+/// Dependency-free SHA-256 (FIPS 180-4), scoped to what Stage 5 needs:
+/// hashing canonicalized JCS byte strings and Merkle append preimages
+/// (`prev_root ++ digest`). Not optimized; correctness and
+/// determinism matter far more than speed for a refusal-contract primitive.
+mod sha256 {
+    const H0: [u32; 8] = [
+        0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab,
+        0x5be0cd19,
+    ];
+
+    const K: [u32; 64] = [
+        0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4,
+        0xab1c5ed5, 0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe,
+        0x9bdc06a7, 0xc19bf174, 0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f,
+        0x4a7484aa, 0x5cb0a9dc, 0x76f988da, 0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7,
+        0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967, 0x27b70a85, 0x2e1b2138, 0x4d2c6dfc,
+        0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85, 0xa2bfe8a1, 0xa81a664b,
+        0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070, 0x19a4c116,
+        0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+        0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7,
+        0xc67178f2,
+    ];
+
+    /// One-shot SHA-256 over `data`, returning the 32-byte digest.
+    pub fn digest(data: &[u8]) -> [u8; 32] {
+        let mut h = H0;
+
+        let bit_len = (data.len() as u64) * 8;
+        let mut msg = data.to_vec();
+        msg.push(0x80);
+        while msg.len() % 64 != 56 {
+            msg.push(0);
+        }
+        msg.extend_from_slice(&bit_len.to_be_bytes());
+
+        for chunk in msg.chunks_exact(64) {
+            let mut w = [0u32; 64];
+            for (i, word) in chunk.chunks_exact(4).enumerate() {
+                w[i] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+            }
+            for i in 16..64 {
+                let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+                let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+                w[i] = w[i - 16]
+                    .wrapping_add(s0)
+                    .wrapping_add(w[i - 7])
+                    .wrapping_add(s1);
+            }
+
+            let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut hh] = h;
+            for i in 0..64 {
+                let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+                let ch = (e & f) ^ ((!e) & g);
+                let t1 = hh
+                    .wrapping_add(s1)
+                    .wrapping_add(ch)
+                    .wrapping_add(K[i])
+                    .wrapping_add(w[i]);
+                let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+                let maj = (a & b) ^ (a & c) ^ (b & c);
+                let t2 = s0.wrapping_add(maj);
+
+                hh = g;
+                g = f;
+                f = e;
+                e = d.wrapping_add(t1);
+                d = c;
+                c = b;
+                b = a;
+                a = t1.wrapping_add(t2);
+            }
+
+            h[0] = h[0].wrapping_add(a);
+            h[1] = h[1].wrapping_add(b);
+            h[2] = h[2].wrapping_add(c);
+            h[3] = h[3].wrapping_add(d);
+            h[4] = h[4].wrapping_add(e);
+            h[5] = h[5].wrapping_add(f);
+            h[6] = h[6].wrapping_add(g);
+            h[7] = h[7].wrapping_add(hh);
+        }
+
+        let mut out = [0u8; 32];
+        for (i, word) in h.iter().enumerate() {
+            out[i * 4..i * 4 + 4].copy_from_slice(&word.to_be_bytes());
+        }
+        out
+    }
+}
+
+/// Build the JCS value tree for the "anchor" fields of a `SceneCapsule` —
+/// i.e. everything except fields that are *derived from* the capsule itself
+/// (`merkle_root`), which must never be folded into their own input.
+///
+/// Anchors (hashed): `scene_id`, `world_id`, `corridor_id`, `finality_tag`,
+/// `genesis_hash_sha256`, `vaulted_blob_sha256`, `prev_root`,
+/// `stage3_impossible_worldline`.
+/// Derived (excluded): `merkle_root` (computed from this digest via the
+/// Merkle append recurrence in `verify_corridor_chain`).
+///
+/// Key order below is the UTF-16 code-unit ascending order JCS requires:
+/// corridor_id < finality_tag < genesis_hash_sha256 < prev_root < scene_id
+/// < stage3_impossible_worldline < vaulted_blob_sha256 < world_id.
+fn scene_capsule_anchors(sc: &SceneCapsule) -> jcs::Value {
+    jcs::Value::Object(vec![
+        ("corridor_id", jcs::Value::Str(sc.corridor_id.clone())),
+        ("finality_tag", jcs::Value::Str(sc.finality_tag.clone())),
+        (
+            "genesis_hash_sha256",
+            jcs::Value::Hex(sc.genesis_hash_sha256.to_vec()),
+        ),
+        ("prev_root", jcs::Value::Hex(sc.prev_root.to_vec())),
+        ("scene_id", jcs::Value::Str(sc.scene_id.clone())),
+        (
+            "stage3_impossible_worldline",
+            jcs::Value::Bool(sc.stage3_impossible_worldline),
+        ),
+        (
+            "vaulted_blob_sha256",
+            jcs::Value::Hex(sc.vaulted_blob_sha256.to_vec()),
+        ),
+        ("world_id", jcs::Value::Str(sc.world_id.clone())),
+    ])
+}
+
+/// Compute the canonical digest of a `SceneCapsule`: `sha256(JCS(anchors))`.
+/// Pure function — never mutates `sc`, never touches `merkle_root`.
+pub fn digest_scene_capsule(sc: &SceneCapsule) -> CapsuleDigest {
+    let canon = jcs::canonicalize(&scene_capsule_anchors(sc));
+    CapsuleDigest(sha256::digest(&canon))
+}
+
+/// Build the JCS value tree for the runtime parameters that `EmulatorConfig
+/// .config_hash_sha256` is supposed to seal: `max_frame_delta_us`,
+/// `kaiser_floor`, and every field of the `RefusalPolicy` in force.
+/// `build_id` and `config_hash_sha256` itself are not runtime parameters and
+/// are excluded.
+///
+/// Key order below is the UTF-16 code-unit ascending order JCS requires:
+/// acetaldehyde_ppm_max < ethanol_ppm_max < instrument_drift_mm_max
+/// < kaiser_floor < kaiser_floor_min < max_frame_delta_us.
+fn runtime_params_anchors(cfg: &EmulatorConfig, policy: &RefusalPolicy) -> jcs::Value {
+    jcs::Value::Object(vec![
+        (
+            "acetaldehyde_ppm_max",
+            jcs::Value::F64Bits(policy.acetaldehyde_ppm_max),
+        ),
+        ("ethanol_ppm_max", jcs::Value::F64Bits(policy.ethanol_ppm_max)),
+        (
+            "instrument_drift_mm_max",
+            jcs::Value::F64Bits(policy.instrument_drift_mm_max),
+        ),
+        ("kaiser_floor", jcs::Value::F64Bits(cfg.kaiser_floor)),
+        (
+            "kaiser_floor_min",
+            jcs::Value::F64Bits(policy.kaiser_floor_min),
+        ),
+        (
+            "max_frame_delta_us",
+            jcs::Value::UInt(cfg.max_frame_delta_us as u64),
+        ),
+    ])
+}
+
+/// Compute the canonical digest of the runtime parameters bound to `cfg`:
+/// `sha256(JCS(runtime_params_anchors))`. Pure function.
+pub fn digest_runtime_params(cfg: &EmulatorConfig, policy: &RefusalPolicy) -> CapsuleDigest {
+    let canon = jcs::canonicalize(&runtime_params_anchors(cfg, policy));
+    CapsuleDigest(sha256::digest(&canon))
+}
+
+/// Verify that `cfg.config_hash_sha256` actually matches the runtime
+/// parameters in force. A mismatch means the policy enforced here is not the
+/// one the capsule was sealed under — stale or swapped config, i.e. replay.
+pub fn verify_config_binding(
+    cfg: &EmulatorConfig,
+    policy: &RefusalPolicy,
+) -> Result<(), RefusalFinding> {
+    let expected = digest_runtime_params(cfg, policy);
+    if expected.0 != cfg.config_hash_sha256 {
+        return Err(RefusalFinding {
+            class: ContaminationClass::ConfigDrift,
+            detail: "config_hash_sha256 does not match recomputed runtime-parameter digest",
+        });
+    }
+    Ok(())
+}
+
+const ZEROED_ROOT: [u8; 32] = [0u8; 32];
+
+/// Verify full Merkle continuity across an ordered corridor/Windchill-ledger
+/// chain, replacing the degenerate single-capsule posture check with the
+/// real append recurrence.
+///
+/// For each `capsule[n]` with `n > 0`:
+/// - `capsule[n].prev_root` must equal `capsule[n - 1].merkle_root`.
+/// - `capsule[n].merkle_root` must equal
+///   `sha256(capsule[n].prev_root ++ digest_scene_capsule(&capsule[n]))`.
+///
+/// The genesis capsule (`n == 0`) is valid only when its `prev_root` is the
+/// zeroed root or equals its own `genesis_hash_sha256`.
+///
+/// A zeroed `prev_root` on any capsule with `n > 0` is a broken link.
+///
+/// Returns the first broken link found, as a deterministic static-detail
+/// `LineageBreak` finding. An empty chain is trivially valid; a
+/// single-capsule chain is checked for genesis posture only (there is
+/// nothing to link).
+pub fn verify_corridor_chain(chain: &[SceneCapsule]) -> Result<(), RefusalFinding> {
+    if let Some(genesis) = chain.first() {
+        let genesis_posture_ok =
+            genesis.prev_root == ZEROED_ROOT || genesis.prev_root == genesis.genesis_hash_sha256;
+        if !genesis_posture_ok {
+            return Err(RefusalFinding {
+                class: ContaminationClass::LineageBreak,
+                detail: "Genesis capsule prev_root is neither zeroed nor genesis_hash_sha256",
+            });
+        }
+    }
+
+    for n in 1..chain.len() {
+        let prev = &chain[n - 1];
+        let cur = &chain[n];
+
+        if cur.prev_root == ZEROED_ROOT {
+            return Err(RefusalFinding {
+                class: ContaminationClass::LineageBreak,
+                detail: "Non-genesis capsule has zeroed prev_root",
+            });
+        }
+
+        if cur.prev_root != prev.merkle_root {
+            return Err(RefusalFinding {
+                class: ContaminationClass::LineageBreak,
+                detail: "Chain link broken: prev_root does not match predecessor merkle_root",
+            });
+        }
+
+        let mut preimage = Vec::with_capacity(32 + 32);
+        preimage.extend_from_slice(&cur.prev_root);
+        preimage.extend_from_slice(&digest_scene_capsule(cur).0);
+        let expected_root = sha256::digest(&preimage);
+
+        if cur.merkle_root != expected_root {
+            return Err(RefusalFinding {
+                class: ContaminationClass::LineageBreak,
+                detail: "Chain link broken: merkle_root does not match recomputed append hash",
+            });
+        }
+    }
+
+    Ok(())
 }
 
 /// Stage-5: evaluate contamination refusal.
 /// Key property: does NOT mutate SceneCapsule.
+///
+/// `chain` is an optional predecessor window ending at (and including)
+/// `scene_before`: when present, `verify_corridor_chain` enforces
+/// multi-capsule continuity instead of just single-capsule posture.
 pub fn stage5_refusal_contract(
     scene_before: &SceneCapsule,
     scene_after: &SceneCapsule,
     cfg: &EmulatorConfig,
     tel: &ContaminationTelemetry,
     policy: &RefusalPolicy,
+    chain: Option<&[SceneCapsule]>,
 ) -> RefusalVerdict {
     // --- Pure-function invariant: before == after on digest ---
     // (You should also enforce deep equality if you want, but digest is the corridor primitive.)
-    let before_digest = digest_scene_capsule_placeholder(scene_before);
-    let after_digest = digest_scene_capsule_placeholder(scene_after);
+    let before_digest = digest_scene_capsule(scene_before);
+    let after_digest = digest_scene_capsule(scene_after);
 
     let mut findings: Vec<RefusalFinding> = Vec::new();
 
@@ -184,20 +532,30 @@ pub fn stage5_refusal_contract(
     }
 
     // 2) Lineage break (Merkle continuity).
-    // For Stage 5, we only check local continuity primitive:
-    // - if prev_root == merkle_root (degenerate) or both zeroed, treat as break unless genesis.
-    // You can replace this with full chain verification via your vault verifier.
-    if scene_before.prev_root == [0u8; 32] && scene_before.merkle_root == [0u8; 32] {
-        findings.push(RefusalFinding {
-            class: ContaminationClass::LineageBreak,
-            detail: "Merkle anchors are zeroed (lineage undefined)",
-        });
-    }
-    if scene_before.prev_root == scene_before.merkle_root {
-        findings.push(RefusalFinding {
-            class: ContaminationClass::LineageBreak,
-            detail: "prev_root equals merkle_root (degenerate linkage)",
-        });
+    match chain {
+        // Full chain verification via `verify_corridor_chain` when a
+        // predecessor window is supplied.
+        Some(chain) => {
+            if let Err(finding) = verify_corridor_chain(chain) {
+                findings.push(finding);
+            }
+        }
+        // No chain window: fall back to the single-capsule posture primitive
+        // (zeroed or degenerate self-referential anchors).
+        None => {
+            if scene_before.prev_root == ZEROED_ROOT && scene_before.merkle_root == ZEROED_ROOT {
+                findings.push(RefusalFinding {
+                    class: ContaminationClass::LineageBreak,
+                    detail: "Merkle anchors are zeroed (lineage undefined)",
+                });
+            }
+            if scene_before.prev_root == scene_before.merkle_root {
+                findings.push(RefusalFinding {
+                    class: ContaminationClass::LineageBreak,
+                    detail: "prev_root equals merkle_root (degenerate linkage)",
+                });
+            }
+        }
     }
 
     // 3) Biological intrusion
@@ -232,6 +590,12 @@ pub fn stage5_refusal_contract(
         });
     }
 
+    // 7) Config binding: the sealed config_hash_sha256 must match the
+    // runtime parameters actually in force (prevents replay/config drift).
+    if let Err(finding) = verify_config_binding(cfg, policy) {
+        findings.push(finding);
+    }
+
     // Deterministic ordering: sort by enum discriminant then by detail pointer address is stable enough,
     // but we avoid relying on pointer ordering. Instead: stable manual ordering by class priority.
     // (Rust enum order is stable within a compilation unit; we keep it explicit anyway.)
@@ -239,6 +603,7 @@ pub fn stage5_refusal_contract(
         ContaminationClass::BiologicalIntrusion => 10,
         ContaminationClass::ChemicalSpike => 20,
         ContaminationClass::InstrumentDrift => 30,
+        ContaminationClass::ConfigDrift => 35,
         ContaminationClass::LineageBreak => 40,
         ContaminationClass::WorldlineImpossibility => 50,
     });
@@ -265,6 +630,74 @@ pub fn enforce_halted_forbids(phase: ArmPhase) -> bool {
     matches!(phase, ArmPhase::Halted)
 }
 
+/// Owns the sorted findings from a HALTed `RefusalVerdict`. Converting a
+/// verdict via `RefusalVerdict::into_result` threads the findings through a
+/// machine-stable `Result` so a guard site can't silently proceed past a
+/// populated findings list by misreading `.ok` or dropping the verdict.
+#[derive(Clone, PartialEq, Eq)]
+pub struct RefusalError {
+    pub findings: Vec<RefusalFinding>,
+}
+
+impl RefusalError {
+    /// `CODE: DETAIL`, one finding per line, in the verdict's sorted order.
+    fn fmt_lines(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, finding) in self.findings.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}: {}", finding.class.code(), finding.detail)?;
+        }
+        Ok(())
+    }
+}
+
+/// Deterministic `CODE: DETAIL` lines — safe to log or ship over the wire
+/// without reformatting.
+impl fmt::Display for RefusalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_lines(f)
+    }
+}
+
+/// Mirrors `Display`: a populated `RefusalError` must never silently render
+/// as an opaque derived struct dump in logs.
+impl fmt::Debug for RefusalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_lines(f)
+    }
+}
+
+impl RefusalVerdict {
+    /// Convert to a `Result` so downstream code is forced to branch on
+    /// HALT via `?`/`match` instead of trusting a caller to check `.ok`.
+    pub fn into_result(self) -> Result<ArmPhase, RefusalError> {
+        if self.ok {
+            Ok(self.next_phase)
+        } else {
+            Err(RefusalError {
+                findings: self.findings,
+            })
+        }
+    }
+}
+
+/// Lets guard sites call `.enforce_halted_forbids()` directly on the
+/// `Result` from `RefusalVerdict::into_result`, so there's no window where
+/// the `Result` is matched without also checking HALT posture.
+pub trait EnforceHaltedForbids {
+    fn enforce_halted_forbids(&self) -> bool;
+}
+
+impl EnforceHaltedForbids for Result<ArmPhase, RefusalError> {
+    fn enforce_halted_forbids(&self) -> bool {
+        match self {
+            Ok(phase) => enforce_halted_forbids(*phase),
+            Err(_) => true,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -283,13 +716,17 @@ mod tests {
         }
     }
 
-    fn mk_cfg() -> EmulatorConfig {
-        EmulatorConfig {
+    /// Build an `EmulatorConfig` whose `config_hash_sha256` is sealed
+    /// correctly against `policy`, i.e. `verify_config_binding` passes.
+    fn mk_cfg(policy: &RefusalPolicy) -> EmulatorConfig {
+        let mut cfg = EmulatorConfig {
             build_id: [9u8; 20],
-            config_hash_sha256: [8u8; 32],
+            config_hash_sha256: [0u8; 32],
             max_frame_delta_us: 1490,
             kaiser_floor: 0.985,
-        }
+        };
+        cfg.config_hash_sha256 = digest_runtime_params(&cfg, policy).0;
+        cfg
     }
 
     fn mk_policy() -> RefusalPolicy {
@@ -304,8 +741,8 @@ mod tests {
     #[test]
     fn passes_when_clean_and_pure() {
         let scene = mk_scene();
-        let cfg = mk_cfg();
         let policy = mk_policy();
+        let cfg = mk_cfg(&policy);
         let tel = ContaminationTelemetry {
             acetaldehyde_ppm: 10.0,
             ethanol_ppm: 200.0,
@@ -314,18 +751,102 @@ mod tests {
             worldline_impossible_flag: false,
         };
 
-        let verdict = stage5_refusal_contract(&scene, &scene, &cfg, &tel, &policy);
+        let verdict = stage5_refusal_contract(&scene, &scene, &cfg, &tel, &policy, None);
         assert!(verdict.ok);
         assert_eq!(verdict.next_phase, ArmPhase::Verifying);
         assert!(verdict.findings.is_empty());
         assert!(!enforce_halted_forbids(verdict.next_phase));
     }
 
+    #[test]
+    fn halts_on_config_hash_mismatch() {
+        let scene = mk_scene();
+        let policy = mk_policy();
+        let mut cfg = mk_cfg(&policy);
+        // Simulate a swapped/stale sealed config: the hash no longer matches
+        // the runtime parameters actually in force.
+        cfg.config_hash_sha256[0] ^= 0xFF;
+        let tel = ContaminationTelemetry {
+            acetaldehyde_ppm: 10.0,
+            ethanol_ppm: 200.0,
+            instrument_drift_mm: 0.0,
+            biological_intrusion_flag: false,
+            worldline_impossible_flag: false,
+        };
+
+        let verdict = stage5_refusal_contract(&scene, &scene, &cfg, &tel, &policy, None);
+        assert!(!verdict.ok);
+        assert_eq!(verdict.next_phase, ArmPhase::Halted);
+        assert!(verdict
+            .findings
+            .iter()
+            .any(|f| f.class == ContaminationClass::ConfigDrift));
+    }
+
+    #[test]
+    fn verify_config_binding_accepts_sealed_config() {
+        let policy = mk_policy();
+        let cfg = mk_cfg(&policy);
+        assert!(verify_config_binding(&cfg, &policy).is_ok());
+    }
+
+    #[test]
+    fn contamination_class_codes_are_stable() {
+        assert_eq!(ContaminationClass::BiologicalIntrusion.code(), 1);
+        assert_eq!(ContaminationClass::ChemicalSpike.code(), 2);
+        assert_eq!(ContaminationClass::InstrumentDrift.code(), 3);
+        assert_eq!(ContaminationClass::LineageBreak.code(), 4);
+        assert_eq!(ContaminationClass::WorldlineImpossibility.code(), 5);
+        assert_eq!(ContaminationClass::ConfigDrift.code(), 6);
+    }
+
+    #[test]
+    fn into_result_ok_on_clean_verdict() {
+        let scene = mk_scene();
+        let policy = mk_policy();
+        let cfg = mk_cfg(&policy);
+        let tel = ContaminationTelemetry {
+            acetaldehyde_ppm: 10.0,
+            ethanol_ppm: 200.0,
+            instrument_drift_mm: 0.0,
+            biological_intrusion_flag: false,
+            worldline_impossible_flag: false,
+        };
+
+        let result =
+            stage5_refusal_contract(&scene, &scene, &cfg, &tel, &policy, None).into_result();
+        assert_eq!(result, Ok(ArmPhase::Verifying));
+        assert!(!result.enforce_halted_forbids());
+    }
+
+    #[test]
+    fn into_result_err_on_halt_with_stable_display_and_debug() {
+        let scene = mk_scene();
+        let policy = mk_policy();
+        let cfg = mk_cfg(&policy);
+        let tel = ContaminationTelemetry {
+            acetaldehyde_ppm: 999.0,
+            ethanol_ppm: 200.0,
+            instrument_drift_mm: 0.0,
+            biological_intrusion_flag: false,
+            worldline_impossible_flag: false,
+        };
+
+        let result =
+            stage5_refusal_contract(&scene, &scene, &cfg, &tel, &policy, None).into_result();
+        assert!(result.enforce_halted_forbids());
+
+        let err = result.unwrap_err();
+        assert_eq!(err.findings.len(), 1);
+        assert_eq!(format!("{err}"), "2: Chemical spike exceeds policy thresholds");
+        assert_eq!(format!("{err:?}"), format!("{err}"));
+    }
+
     #[test]
     fn halts_on_chemical_spike() {
         let scene = mk_scene();
-        let cfg = mk_cfg();
         let policy = mk_policy();
+        let cfg = mk_cfg(&policy);
         let tel = ContaminationTelemetry {
             acetaldehyde_ppm: 999.0,
             ethanol_ppm: 200.0,
@@ -334,7 +855,7 @@ mod tests {
             worldline_impossible_flag: false,
         };
 
-        let verdict = stage5_refusal_contract(&scene, &scene, &cfg, &tel, &policy);
+        let verdict = stage5_refusal_contract(&scene, &scene, &cfg, &tel, &policy, None);
         assert!(!verdict.ok);
         assert_eq!(verdict.next_phase, ArmPhase::Halted);
         assert!(enforce_halted_forbids(verdict.next_phase));
@@ -351,8 +872,8 @@ mod tests {
         // simulate mutation
         after.scene_id = "batavia.1924.fermentation.v1.MUTATED".to_string();
 
-        let cfg = mk_cfg();
         let policy = mk_policy();
+        let cfg = mk_cfg(&policy);
         let tel = ContaminationTelemetry {
             acetaldehyde_ppm: 10.0,
             ethanol_ppm: 200.0,
@@ -362,7 +883,7 @@ mod tests {
         };
 
         // Even with clean telemetry, mutation forces HALT.
-        let verdict = stage5_refusal_contract(&before, &after, &cfg, &tel, &policy);
+        let verdict = stage5_refusal_contract(&before, &after, &cfg, &tel, &policy, None);
         assert!(!verdict.ok);
         assert_eq!(verdict.next_phase, ArmPhase::Halted);
         assert!(verdict
@@ -371,13 +892,66 @@ mod tests {
             .any(|f| f.detail.contains("before != after")));
     }
 
+    #[test]
+    fn digest_changes_when_any_single_anchor_field_differs() {
+        let base = mk_scene();
+        let base_digest = digest_scene_capsule(&base);
+
+        let mut scene_id = base.clone();
+        scene_id.scene_id.push_str(".v2");
+        assert_ne!(digest_scene_capsule(&scene_id), base_digest);
+
+        let mut world_id = base.clone();
+        world_id.world_id.push_str(".v2");
+        assert_ne!(digest_scene_capsule(&world_id), base_digest);
+
+        let mut corridor_id = base.clone();
+        corridor_id.corridor_id.push_str(".v2");
+        assert_ne!(digest_scene_capsule(&corridor_id), base_digest);
+
+        let mut finality_tag = base.clone();
+        finality_tag.finality_tag = "2027.GOLD".to_string();
+        assert_ne!(digest_scene_capsule(&finality_tag), base_digest);
+
+        let mut genesis_hash = base.clone();
+        genesis_hash.genesis_hash_sha256[31] ^= 0xFF;
+        assert_ne!(digest_scene_capsule(&genesis_hash), base_digest);
+
+        let mut vaulted_blob = base.clone();
+        vaulted_blob.vaulted_blob_sha256[31] ^= 0xFF;
+        assert_ne!(digest_scene_capsule(&vaulted_blob), base_digest);
+
+        let mut prev_root = base.clone();
+        prev_root.prev_root[31] ^= 0xFF;
+        assert_ne!(digest_scene_capsule(&prev_root), base_digest);
+
+        let mut worldline = base.clone();
+        worldline.stage3_impossible_worldline = !worldline.stage3_impossible_worldline;
+        assert_ne!(digest_scene_capsule(&worldline), base_digest);
+    }
+
+    #[test]
+    fn digest_excludes_merkle_root_since_it_is_derived() {
+        let mut a = mk_scene();
+        let mut b = mk_scene();
+        a.merkle_root = [0xAAu8; 32];
+        b.merkle_root = [0xBBu8; 32];
+        assert_eq!(digest_scene_capsule(&a), digest_scene_capsule(&b));
+    }
+
+    #[test]
+    fn digest_is_deterministic() {
+        let scene = mk_scene();
+        assert_eq!(digest_scene_capsule(&scene), digest_scene_capsule(&scene));
+    }
+
     #[test]
     fn halts_on_stage3_impossible_worldline() {
         let mut scene = mk_scene();
         scene.stage3_impossible_worldline = true;
 
-        let cfg = mk_cfg();
         let policy = mk_policy();
+        let cfg = mk_cfg(&policy);
         let tel = ContaminationTelemetry {
             acetaldehyde_ppm: 10.0,
             ethanol_ppm: 200.0,
@@ -386,7 +960,7 @@ mod tests {
             worldline_impossible_flag: false,
         };
 
-        let verdict = stage5_refusal_contract(&scene, &scene, &cfg, &tel, &policy);
+        let verdict = stage5_refusal_contract(&scene, &scene, &cfg, &tel, &policy, None);
         assert!(!verdict.ok);
         assert_eq!(verdict.next_phase, ArmPhase::Halted);
         assert!(verdict
@@ -394,4 +968,251 @@ mod tests {
             .iter()
             .any(|f| f.class == ContaminationClass::WorldlineImpossibility));
     }
+
+    /// Build the `n`-th capsule of a valid corridor chain, linked to `prev`
+    /// via the append recurrence (`prev` is `None` for the genesis capsule).
+    fn mk_linked_scene(prev: Option<&SceneCapsule>, n: usize) -> SceneCapsule {
+        let mut sc = mk_scene();
+        sc.scene_id = format!("batavia.1924.fermentation.v{n}");
+        sc.prev_root = match prev {
+            Some(p) => p.merkle_root,
+            None => sc.genesis_hash_sha256,
+        };
+        sc.merkle_root = [0u8; 32]; // placeholder, recomputed below
+        let mut preimage = Vec::new();
+        preimage.extend_from_slice(&sc.prev_root);
+        preimage.extend_from_slice(&digest_scene_capsule(&sc).0);
+        sc.merkle_root = sha256::digest(&preimage);
+        sc
+    }
+
+    fn mk_valid_chain(len: usize) -> Vec<SceneCapsule> {
+        let mut chain = Vec::with_capacity(len);
+        for n in 0..len {
+            let linked = mk_linked_scene(chain.last(), n);
+            chain.push(linked);
+        }
+        chain
+    }
+
+    #[test]
+    fn verify_corridor_chain_accepts_valid_chain() {
+        let chain = mk_valid_chain(4);
+        assert!(verify_corridor_chain(&chain).is_ok());
+    }
+
+    #[test]
+    fn verify_corridor_chain_rejects_tampered_middle_capsule() {
+        let mut chain = mk_valid_chain(4);
+        chain[2].scene_id.push_str(".TAMPERED");
+        let err = verify_corridor_chain(&chain).unwrap_err();
+        assert_eq!(err.class, ContaminationClass::LineageBreak);
+    }
+
+    #[test]
+    fn verify_corridor_chain_rejects_reordered_chain() {
+        let mut chain = mk_valid_chain(4);
+        chain.swap(1, 2);
+        let err = verify_corridor_chain(&chain).unwrap_err();
+        assert_eq!(err.class, ContaminationClass::LineageBreak);
+    }
+
+    #[test]
+    fn verify_corridor_chain_rejects_non_genesis_zeroed_prev_root() {
+        let mut chain = mk_valid_chain(2);
+        // Zeroed prev_root is only a valid posture for the genesis capsule.
+        chain[1].prev_root = [0u8; 32];
+        let err = verify_corridor_chain(&chain).unwrap_err();
+        assert_eq!(err.class, ContaminationClass::LineageBreak);
+    }
+
+    #[test]
+    fn verify_corridor_chain_accepts_zeroed_prev_root_for_genesis() {
+        let mut chain = mk_valid_chain(1);
+        chain[0].prev_root = [0u8; 32];
+        assert!(verify_corridor_chain(&chain).is_ok());
+    }
+
+    #[test]
+    fn stage5_refusal_contract_honors_chain_window() {
+        let chain = mk_valid_chain(3);
+        let policy = mk_policy();
+        let cfg = mk_cfg(&policy);
+        let tel = ContaminationTelemetry {
+            acetaldehyde_ppm: 10.0,
+            ethanol_ppm: 200.0,
+            instrument_drift_mm: 0.0,
+            biological_intrusion_flag: false,
+            worldline_impossible_flag: false,
+        };
+
+        let last = chain.last().unwrap();
+        let verdict =
+            stage5_refusal_contract(last, last, &cfg, &tel, &policy, Some(&chain));
+        assert!(verdict.ok, "valid chain should not trigger LineageBreak");
+
+        let mut broken = chain.clone();
+        broken[1].scene_id.push_str(".TAMPERED");
+        let last = broken.last().unwrap();
+        let verdict =
+            stage5_refusal_contract(last, last, &cfg, &tel, &policy, Some(&broken));
+        assert!(!verdict.ok);
+        assert!(verdict
+            .findings
+            .iter()
+            .any(|f| f.class == ContaminationClass::LineageBreak));
+    }
+}
+
+/// Property-based mirror of the `fuzz/fuzz_targets/stage5_refusal.rs`
+/// invariants, so they run under normal `cargo test` without requiring the
+/// honggfuzz toolchain. Keep these two in lockstep: if you add an invariant
+/// to one, add it to the other.
+#[cfg(test)]
+mod property_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn arb_f64() -> impl Strategy<Value = f64> {
+        // Covers NaN/inf/subnormals, not just "nice" floats: the invariants
+        // must hold even when telemetry or thresholds are non-finite.
+        prop::num::f64::ANY
+    }
+
+    fn arb_scene() -> impl Strategy<Value = SceneCapsule> {
+        (
+            ".*",
+            ".*",
+            ".*",
+            ".*",
+            any::<[u8; 32]>(),
+            any::<[u8; 32]>(),
+            any::<[u8; 32]>(),
+            any::<[u8; 32]>(),
+            any::<bool>(),
+        )
+            .prop_map(
+                |(
+                    scene_id,
+                    world_id,
+                    corridor_id,
+                    finality_tag,
+                    genesis_hash_sha256,
+                    vaulted_blob_sha256,
+                    merkle_root,
+                    prev_root,
+                    stage3_impossible_worldline,
+                )| SceneCapsule {
+                    scene_id,
+                    world_id,
+                    corridor_id,
+                    finality_tag,
+                    genesis_hash_sha256,
+                    vaulted_blob_sha256,
+                    merkle_root,
+                    prev_root,
+                    stage3_impossible_worldline,
+                },
+            )
+    }
+
+    fn arb_cfg() -> impl Strategy<Value = EmulatorConfig> {
+        (
+            any::<[u8; 20]>(),
+            any::<[u8; 32]>(),
+            any::<u32>(),
+            arb_f64(),
+        )
+            .prop_map(
+                |(build_id, config_hash_sha256, max_frame_delta_us, kaiser_floor)| EmulatorConfig {
+                    build_id,
+                    config_hash_sha256,
+                    max_frame_delta_us,
+                    kaiser_floor,
+                },
+            )
+    }
+
+    fn arb_tel() -> impl Strategy<Value = ContaminationTelemetry> {
+        (
+            arb_f64(),
+            arb_f64(),
+            arb_f64(),
+            any::<bool>(),
+            any::<bool>(),
+        )
+            .prop_map(
+                |(
+                    acetaldehyde_ppm,
+                    ethanol_ppm,
+                    instrument_drift_mm,
+                    biological_intrusion_flag,
+                    worldline_impossible_flag,
+                )| ContaminationTelemetry {
+                    acetaldehyde_ppm,
+                    ethanol_ppm,
+                    instrument_drift_mm,
+                    biological_intrusion_flag,
+                    worldline_impossible_flag,
+                },
+            )
+    }
+
+    fn arb_policy() -> impl Strategy<Value = RefusalPolicy> {
+        (arb_f64(), arb_f64(), arb_f64(), arb_f64()).prop_map(
+            |(acetaldehyde_ppm_max, ethanol_ppm_max, instrument_drift_mm_max, kaiser_floor_min)| {
+                RefusalPolicy {
+                    acetaldehyde_ppm_max,
+                    ethanol_ppm_max,
+                    instrument_drift_mm_max,
+                    kaiser_floor_min,
+                }
+            },
+        )
+    }
+
+    fn class_priority(f: &RefusalFinding) -> u8 {
+        match f.class {
+            ContaminationClass::BiologicalIntrusion => 10,
+            ContaminationClass::ChemicalSpike => 20,
+            ContaminationClass::InstrumentDrift => 30,
+            ContaminationClass::ConfigDrift => 35,
+            ContaminationClass::LineageBreak => 40,
+            ContaminationClass::WorldlineImpossibility => 50,
+        }
+    }
+
+    proptest! {
+        /// (2) no interior mutation, (3) determinism, (4) non-empty findings
+        /// implies HALT, (5) findings sorted by class priority. (1) "never
+        /// panics" holds implicitly: proptest reports any panic as a failure.
+        #[test]
+        fn refusal_contract_invariants_hold(
+            scene_before in arb_scene(),
+            scene_after in arb_scene(),
+            cfg in arb_cfg(),
+            tel in arb_tel(),
+            policy in arb_policy(),
+        ) {
+            let before_snapshot = scene_before.clone();
+
+            let verdict1 =
+                stage5_refusal_contract(&scene_before, &scene_after, &cfg, &tel, &policy, None);
+            prop_assert_eq!(&scene_before, &before_snapshot);
+
+            let verdict2 =
+                stage5_refusal_contract(&scene_before, &scene_after, &cfg, &tel, &policy, None);
+            prop_assert_eq!(&verdict1, &verdict2);
+
+            if !verdict1.findings.is_empty() {
+                prop_assert!(!verdict1.ok);
+                prop_assert_eq!(verdict1.next_phase, ArmPhase::Halted);
+            }
+
+            prop_assert!(verdict1
+                .findings
+                .windows(2)
+                .all(|w| class_priority(&w[0]) <= class_priority(&w[1])));
+        }
+    }
 }